@@ -1,5 +1,5 @@
 use anyhow::{bail, Result};
-#[cfg(target_os = "windows")]
+#[cfg(any(target_os = "windows", target_os = "linux"))]
 use std::env;
 use std::{fs, path::PathBuf};
 use tracing::{debug, error, info, warn};
@@ -118,11 +118,15 @@ fn get_install_path() -> Result<PathBuf> {
     let tidal_directory: Option<PathBuf> = match which("tidal") {
         Ok(path) => {
             info!("Found Tidal binary at: {:?}", path);
-            match path.parent() {
+            // Many package managers put a symlink on PATH; resolve it to find
+            // the real install root rather than the link's own directory. A
+            // wrapper script is not followed; its own directory is used.
+            let resolved = fs::canonicalize(&path).unwrap_or(path);
+            match resolved.parent() {
                 Some(parent) => Some(parent.to_path_buf()),
                 None => bail!(
                     "Tidal binary path is invalid, cannot find parent folder! {}",
-                    path.display()
+                    resolved.display()
                 ),
             }
         }
@@ -170,17 +174,61 @@ fn get_install_path() -> Result<PathBuf> {
     };
 
     #[cfg(target_os = "linux")]
-    return Ok(match tidal_directory {
-        Some(tidal_directory) => PathBuf::from(format!("{}/resources", tidal_directory.display())),
-        None => {
-            bail!("Cannot find Tidal directory");
-        }
-    });
+    return find_linux_install_path(tidal_directory);
 
     #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
     bail!("OS not supported! Please specify your Tidal installation path (location of app.asar) and consider opening a issue on GitHub.");
 }
 
+// Probes the install layouts used by the common Linux packagings of Tidal,
+// returning the first directory that actually contains `app.asar`. `tidal_directory`
+// (the resolved parent of a `tidal` binary found on PATH, if any) is checked first
+// since it's the most specific hint; then the hardcoded Flatpak paths, the AppImage
+// layouts under `tidal_directory`, and the hardcoded distro-package paths.
+#[cfg(target_os = "linux")]
+fn find_linux_install_path(tidal_directory: Option<PathBuf>) -> Result<PathBuf> {
+    let mut candidates = Vec::new();
+
+    if let Some(tidal_directory) = &tidal_directory {
+        candidates.push(tidal_directory.join("resources"));
+        candidates.push(tidal_directory.clone());
+    }
+
+    if let Some(home) = env::var_os("HOME").map(PathBuf::from) {
+        // Flatpak (per-user install)
+        candidates.push(
+            home.join(".var/app/com.tidal.TIDAL/current/active/files/resources"),
+        );
+    }
+
+    // Flatpak (system-wide install)
+    candidates.push(PathBuf::from(
+        "/var/lib/flatpak/app/com.tidal.TIDAL/current/active/files/resources",
+    ));
+
+    // AppImages are typically extracted (or mounted) next to the binary found
+    // on PATH, with the app contents under a squashfs-root/usr tree.
+    if let Some(tidal_directory) = &tidal_directory {
+        candidates.push(tidal_directory.join("usr/resources"));
+        candidates.push(tidal_directory.join("squashfs-root/usr/resources"));
+    }
+
+    // Plain tarball/distro-package installs
+    candidates.push(PathBuf::from("/opt/tidal/resources"));
+    candidates.push(PathBuf::from("/usr/lib/tidal/resources"));
+
+    for candidate in candidates {
+        if candidate.join("app.asar").exists() {
+            return Ok(candidate);
+        }
+    }
+
+    bail!(
+        "Could not find a Tidal install with app.asar. Checked Flatpak, AppImage, /opt/tidal and \
+        /usr/lib/tidal locations; pass --install-path to point at it directly."
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +263,25 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_find_linux_install_path_picks_candidate_with_app_asar() -> Result<()> {
+        let tidal_directory = TempDir::new()?.into_path();
+        let resources = tidal_directory.join("resources");
+        fs::create_dir_all(&resources)?;
+        fs::File::create(resources.join("app.asar"))?;
+
+        let found = find_linux_install_path(Some(tidal_directory))?;
+        assert_eq!(found, resources);
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_find_linux_install_path_errs_when_nothing_found() {
+        let tidal_directory = TempDir::new().unwrap().into_path();
+        assert!(find_linux_install_path(Some(tidal_directory)).is_err());
+    }
 }
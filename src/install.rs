@@ -1,16 +1,51 @@
 use anyhow::{Context, Result};
+use directories::ProjectDirs;
 use ripunzip::{UnzipEngine, UnzipOptions};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 use crate::base::LunaInstall;
-use crate::progress::ProgressDisplayer;
+use crate::manifest;
+use crate::progress::{DownloadProgressDisplayer, ProgressDisplayer};
+
+/// Bundles the ways a release can be selected/fetched so `install`/`update`
+/// don't have to grow another positional argument every time this gains an option.
+#[derive(Default)]
+pub struct FetchOptions {
+    pub tag: Option<String>,
+    pub prerelease: bool,
+    pub from_file: Option<PathBuf>,
+    pub no_download: bool,
+}
 
 fn report_on_insufficient_readahead_size() {
     warn!("Warning: this operation required several HTTP(S) streams.\nThis can slow down decompression.");
 }
 
+const DOWNLOAD_MAX_ATTEMPTS: u32 = 5;
+
+// Builds the client used for every request. reqwest's blocking ClientBuilder
+// honors HTTP_PROXY/HTTPS_PROXY/NO_PROXY from the environment by default, so
+// there's nothing else to wire up for proxy support.
+fn http_client() -> Result<reqwest::blocking::Client> {
+    reqwest::blocking::Client::builder()
+        .user_agent("neptunectl-beta")
+        .build()
+        .map_err(Into::into)
+}
+
+// luna's Ed25519 release signing key, once one is actually published. Signing
+// isn't set up yet, so this stays `None` rather than embed a placeholder that
+// would either reject every legitimately-signed release or fail to even parse
+// as a valid key - set this to `Some(real_key_bytes)` once luna starts signing
+// releases.
+const LUNA_RELEASE_PUBLIC_KEY: Option<[u8; 32]> = None;
+
 #[derive(Serialize, Deserialize)]
 struct GithubRelease {
     tag_name: String,
@@ -18,41 +53,273 @@ struct GithubRelease {
     draft: bool,
     assets: Vec<GithubAsset>,
 }
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct GithubAsset {
     name: String,
     browser_download_url: String,
 }
 
-// Returns 'version, browser_download_url'
-fn get_latest_release(get_prerelease: bool) -> Result<(String, String)> {
-    let client = reqwest::blocking::Client::new();
+fn find_zip_asset(assets: &[GithubAsset]) -> Option<&GithubAsset> {
+    assets
+        .iter()
+        .find(|asset| asset.name.contains("luna.zip") && asset.name.ends_with(".zip"))
+}
+
+fn find_sibling_asset<'a>(assets: &'a [GithubAsset], zip_name: &str, suffix: &str) -> Option<&'a GithubAsset> {
+    let sibling_name = format!("{zip_name}{suffix}");
+    assets.iter().find(|asset| asset.name == sibling_name)
+}
+
+// Returns 'tag_name, zip_asset, all_assets' so sibling checksum/signature
+// assets published alongside `luna.zip` can be looked up afterwards.
+//
+// When `tag` is given, it takes precedence over `get_prerelease`: the release
+// matching that exact `tag_name` is used regardless of its draft/prerelease
+// status, so a known-good build can be pinned or a bad update rolled back.
+fn get_latest_release(
+    get_prerelease: bool,
+    tag: Option<&str>,
+) -> Result<(String, GithubAsset, Vec<GithubAsset>)> {
+    let client = http_client()?;
     info!("Fetching release metadata");
     let response = client
         .get("https://api.github.com/repos/Inrixia/TidaLuna/releases")
-        .header("user-agent", "neptunectl-beta")
         .send()?;
     info!("Fetched release metadata, decoding response body");
-    let release = response.json::<Vec<GithubRelease>>()?;
+    let releases = response.json::<Vec<GithubRelease>>()?;
     info!("Parsing release metadata");
-    for release in release {
-        if release.prerelease == get_prerelease {
-            for asset in release.assets {
-                if asset.name.contains("luna.zip") {
-                    return Ok((release.tag_name, asset.browser_download_url));
+
+    if let Some(tag) = tag {
+        for release in releases {
+            if release.tag_name == tag {
+                if let Some(zip_asset) = find_zip_asset(&release.assets) {
+                    let zip_asset = zip_asset.clone();
+                    return Ok((release.tag_name, zip_asset, release.assets));
                 }
             }
         }
+        return Err(anyhow::anyhow!(
+            "Failed to find a release tagged {tag} with a luna.zip asset"
+        ));
+    }
+
+    for release in releases {
+        if release.prerelease == get_prerelease {
+            if let Some(zip_asset) = find_zip_asset(&release.assets) {
+                let zip_asset = zip_asset.clone();
+                return Ok((release.tag_name, zip_asset, release.assets));
+            }
+        }
     }
     Err(anyhow::anyhow!(
         "Failed to find luna.zip in latest releases"
     ))
 }
 
-fn download_and_extract(output_directory: &Path) -> Result<()> {
-    // get latest release
-    let (version, url) = get_latest_release(false)?;
-    let engine = UnzipEngine::for_uri(&url, None, report_on_insufficient_readahead_size)
+fn hex_decode(hex: &str) -> Result<Vec<u8>> {
+    let hex = hex.trim();
+    if hex.len() % 2 != 0 {
+        anyhow::bail!("Hex digest has an odd number of characters");
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .with_context(|| format!("Invalid hex digest: {hex}"))
+        })
+        .collect()
+}
+
+// Constant-time comparison so the amount of matching prefix in a corrupted or
+// tampered download can't be inferred from how quickly the check fails.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn download_text(url: &str) -> Result<String> {
+    Ok(http_client()?.get(url).send()?.text()?)
+}
+
+// Downloads `url` to `dest`, resuming from whatever `dest` already contains
+// (via a `Range` request) and hashing the full file contents as it goes.
+// Transient failures are retried with exponential backoff instead of losing
+// the partial download, picking the resume back up on the next attempt.
+fn download_to_file(
+    client: &reqwest::blocking::Client,
+    display_name: &str,
+    url: &str,
+    dest: &Path,
+) -> Result<[u8; 32]> {
+    let mut last_err = None;
+    for attempt in 0..DOWNLOAD_MAX_ATTEMPTS {
+        if attempt > 0 {
+            let backoff = Duration::from_secs(1 << attempt.min(5));
+            warn!(
+                "Download of {url} failed ({}), retrying in {backoff:?} (attempt {}/{DOWNLOAD_MAX_ATTEMPTS})",
+                last_err.as_ref().map(anyhow::Error::to_string).unwrap_or_default(),
+                attempt + 1,
+            );
+            std::thread::sleep(backoff);
+        }
+
+        match try_download_to_file(client, display_name, url, dest) {
+            Ok(digest) => return Ok(digest),
+            Err(e) => last_err = Some(e),
+        }
+    }
+
+    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("Failed to download {url}")))
+}
+
+fn try_download_to_file(
+    client: &reqwest::blocking::Client,
+    display_name: &str,
+    url: &str,
+    dest: &Path,
+) -> Result<[u8; 32]> {
+    let already_downloaded = fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if already_downloaded > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={already_downloaded}-"));
+    }
+    let mut response = request.send()?.error_for_status()?;
+
+    let is_resuming = already_downloaded > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let starting_at = if is_resuming { already_downloaded } else { 0 };
+    let total_bytes = response
+        .content_length()
+        .map(|remaining| remaining + starting_at)
+        .unwrap_or(0);
+    let progress = DownloadProgressDisplayer::new(display_name, total_bytes, starting_at);
+
+    let mut hasher = Sha256::new();
+    let mut file = if is_resuming {
+        debug!("Resuming download of {url} from byte {already_downloaded}");
+        hasher.update(&fs::read(dest)?);
+        OpenOptions::new().append(true).open(dest)?
+    } else {
+        File::create(dest)?
+    };
+
+    let mut buf = [0u8; 8192];
+    loop {
+        let read = response.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        file.write_all(&buf[..read])?;
+        hasher.update(&buf[..read]);
+        progress.inc(read as u64);
+    }
+    progress.finish();
+    Ok(hasher.finalize().into())
+}
+
+fn verify_checksum(assets: &[GithubAsset], zip_asset: &GithubAsset, digest: &[u8; 32]) -> Result<()> {
+    let Some(checksum_asset) = find_sibling_asset(assets, &zip_asset.name, ".sha256") else {
+        warn!(
+            "No {}.sha256 asset published for this release; skipping checksum verification.",
+            zip_asset.name
+        );
+        return Ok(());
+    };
+
+    info!("Verifying {} against published checksum", zip_asset.name);
+    let expected_hex = download_text(&checksum_asset.browser_download_url)?;
+    let expected = hex_decode(expected_hex.split_whitespace().next().unwrap_or(""))?;
+
+    if !constant_time_eq(digest, &expected) {
+        anyhow::bail!(
+            "Checksum mismatch for {}: downloaded file does not match {}",
+            zip_asset.name,
+            checksum_asset.name
+        );
+    }
+
+    Ok(())
+}
+
+fn verify_signature(assets: &[GithubAsset], zip_asset: &GithubAsset, zip_path: &Path) -> Result<()> {
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    let Some(sig_asset) = find_sibling_asset(assets, &zip_asset.name, ".sig") else {
+        return Ok(());
+    };
+
+    let Some(public_key) = LUNA_RELEASE_PUBLIC_KEY else {
+        warn!(
+            "{} published but no release signing key is embedded yet; skipping signature verification.",
+            sig_asset.name
+        );
+        return Ok(());
+    };
+
+    info!("Verifying {} signature", zip_asset.name);
+    let sig_hex = download_text(&sig_asset.browser_download_url)?;
+    let sig_bytes = hex_decode(sig_hex.trim())?;
+    let signature = Signature::from_slice(&sig_bytes)
+        .map_err(|e| anyhow::anyhow!("Malformed signature in {}: {e}", sig_asset.name))?;
+
+    let verifying_key = VerifyingKey::from_bytes(&public_key)
+        .map_err(|e| anyhow::anyhow!("Invalid embedded release public key: {e}"))?;
+
+    let zip_bytes = std::fs::read(zip_path)?;
+    verifying_key
+        .verify(&zip_bytes, &signature)
+        .map_err(|e| anyhow::anyhow!("Signature verification failed for {}: {e}", zip_asset.name))?;
+
+    Ok(())
+}
+
+// Per-user cache of downloaded releases, keyed by tag_name, so repeat
+// installs/reinstalls skip downloading the zip again and `--no-download` runs
+// (with `--tag`) don't need `api.github.com`.
+fn cache_dir() -> Result<PathBuf> {
+    let dirs = ProjectDirs::from("", "", "lunactl")
+        .ok_or_else(|| anyhow::anyhow!("Could not determine a cache directory for this user"))?;
+    let dir = dirs.cache_dir().join("releases");
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+// `tag` comes straight from the GitHub API (or `--tag`), so it has to be
+// validated before being used as a path component - a tag containing `/` or
+// `..` would otherwise let a compromised release feed (or a typo'd `--tag`)
+// write outside `cache_dir()`.
+fn validate_cache_key(tag: &str) -> Result<()> {
+    let is_safe = !tag.is_empty()
+        && tag != "."
+        && tag != ".."
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    if !is_safe {
+        anyhow::bail!("Release tag {tag:?} is not safe to use as a cache filename");
+    }
+    Ok(())
+}
+
+fn cached_zip_path(tag: &str) -> Result<PathBuf> {
+    validate_cache_key(tag)?;
+    Ok(cache_dir()?.join(format!("{tag}.zip")))
+}
+
+// Downloads land here first so a crash/Ctrl-C mid-transfer never leaves a
+// truncated file at `cached_zip_path`'s location; only a file that has passed
+// `verify_checksum`/`verify_signature` ever gets renamed into the real cache
+// entry, so a future `cache_path.exists()` check can keep trusting it blindly.
+fn partial_zip_path(cache_path: &Path) -> PathBuf {
+    let mut partial = cache_path.as_os_str().to_owned();
+    partial.push(".partial");
+    PathBuf::from(partial)
+}
+
+fn unzip_local_file(zip_path: &Path, output_directory: &Path) -> Result<()> {
+    let engine = UnzipEngine::for_file(File::open(zip_path)?, report_on_insufficient_readahead_size)
         .map_err(|e| anyhow::anyhow!("Failed to create UnzipEngine: {e}"))?;
 
     let opts: UnzipOptions = UnzipOptions {
@@ -63,8 +330,6 @@ fn download_and_extract(output_directory: &Path) -> Result<()> {
         progress_reporter: Box::new(ProgressDisplayer::new()),
     };
 
-    info!("Downloading luna version {} ({})", version, url);
-
     engine
         .unzip(opts)
         .map_err(|e| anyhow::anyhow!("Failed to unzip: {e}"))?;
@@ -72,7 +337,136 @@ fn download_and_extract(output_directory: &Path) -> Result<()> {
     Ok(())
 }
 
-pub fn install(install: &LunaInstall, force: bool) -> Result<()> {
+// Returns 'tag_name, browser_download_url' ('local, <path>' for `--from-file`,
+// 'tag, cache:<path>' for a cache-only run). A fresh download is fetched into
+// the per-user cache as a plain file and hashed and verified before anything
+// is unzipped into `output_directory`, so a corrupted or tampered download is
+// caught before `app.asar` is touched. A `--from-file` zip is unzipped as is.
+fn download_and_extract(output_directory: &Path, fetch: &FetchOptions) -> Result<(String, String)> {
+    if let Some(from_file) = &fetch.from_file {
+        info!("Installing luna from local file {}", from_file.display());
+        unzip_local_file(from_file, output_directory)?;
+        return Ok(("local".to_string(), from_file.display().to_string()));
+    }
+
+    let remote_release = if fetch.no_download {
+        None
+    } else {
+        match get_latest_release(fetch.prerelease, fetch.tag.as_deref()) {
+            Ok(release) => Some(release),
+            Err(e) => {
+                warn!("Failed to reach GitHub ({e}), falling back to the local cache");
+                None
+            }
+        }
+    };
+
+    match remote_release {
+        Some((version, zip_asset, assets)) => {
+            let cache_path = cached_zip_path(&version)?;
+            if cache_path.exists() {
+                info!("Using cached copy of {} ({version})", zip_asset.name);
+            } else {
+                let client = http_client()?;
+                // `download_to_file` already degrades gracefully when the server
+                // doesn't honor `Range` (it just restarts the file from scratch
+                // on a non-206 response), so there's no case where a verified,
+                // file-based download isn't viable - unlike the unverified
+                // streaming fallback this used to fall back to.
+                info!(
+                    "Downloading luna version {} ({})",
+                    version, zip_asset.browser_download_url
+                );
+                let partial_path = partial_zip_path(&cache_path);
+                let digest = download_to_file(
+                    &client,
+                    &zip_asset.name,
+                    &zip_asset.browser_download_url,
+                    &partial_path,
+                )?;
+                if let Err(e) = verify_checksum(&assets, &zip_asset, &digest)
+                    .and_then(|_| verify_signature(&assets, &zip_asset, &partial_path))
+                {
+                    let _ = fs::remove_file(&partial_path);
+                    return Err(e);
+                }
+                fs::rename(&partial_path, &cache_path).with_context(|| {
+                    format!(
+                        "Failed to move verified download from {} to {}",
+                        partial_path.display(),
+                        cache_path.display()
+                    )
+                })?;
+            }
+
+            unzip_local_file(&cache_path, output_directory)?;
+            Ok((version, zip_asset.browser_download_url))
+        }
+        None => {
+            let tag = fetch.tag.as_deref().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "--no-download (or an unreachable GitHub) requires --tag to pick a cached release"
+                )
+            })?;
+            let cache_path = cached_zip_path(tag)?;
+            if !cache_path.exists() {
+                anyhow::bail!(
+                    "No cached copy of {tag} found; run once without --no-download to populate the cache"
+                );
+            }
+
+            info!("Using cached copy of {tag}");
+            unzip_local_file(&cache_path, output_directory)?;
+            Ok((tag.to_string(), format!("cache:{}", cache_path.display())))
+        }
+    }
+}
+
+// Downloads & extracts the given release into `install.app_path`, backing up
+// app.asar (if not already done) and recording the install manifest. Used by
+// `install`; `update` replaces the app folder on its own.
+//
+// The download is verified and extracted into `install.temp_path` before app.asar is
+// touched, so a checksum mismatch, bad signature, or corrupted zip leaves the
+// existing (unmodified) install in place instead of a half-installed app.asar
+// with nothing to show for it.
+fn backup_download_and_extract(install: &LunaInstall, fetch: &FetchOptions) -> Result<()> {
+    info!(
+        "Downloading & extracting luna to {}",
+        install.temp_path.display()
+    );
+    let (tag_name, asset_url) = download_and_extract(&install.temp_path, fetch)?;
+    if !install.temp_path.exists() {
+        anyhow::bail!(
+            "luna injector failed to extract to {}",
+            install.temp_path.display()
+        );
+    }
+
+    if !install.orig_asar_path.exists() {
+        info!(
+            "Backing up {} to {}",
+            install.app_asar_path.display(),
+            install.orig_asar_path.display()
+        );
+        std::fs::rename(&install.app_asar_path, &install.orig_asar_path)?;
+    }
+
+    info!("Installing luna to {}", install.app_path.display());
+    std::fs::rename(&install.temp_path, &install.app_path).with_context(|| {
+        format!(
+            "Failed to move injector from {} to {}",
+            install.temp_path.display(),
+            install.app_path.display()
+        )
+    })?;
+
+    manifest::write(install, &tag_name, &asset_url)?;
+
+    Ok(())
+}
+
+pub fn install(install: &LunaInstall, force: bool, fetch: &FetchOptions) -> Result<()> {
     debug!("Using install path: {}", install.install_path.display());
 
     // Check if luna is already installed
@@ -88,21 +482,88 @@ pub fn install(install: &LunaInstall, force: bool) -> Result<()> {
         }
     }
 
-    // check if original app.asar moved
-    if !install.orig_asar_path.exists() {
-        info!(
-            "Backing up {} to {}",
-            install.orig_asar_path.display(),
-            install.app_asar_path.display()
-        );
-        std::fs::rename(&install.app_asar_path, &install.orig_asar_path)?;
+    backup_download_and_extract(install, fetch)?;
+
+    info!("luna has been installed successfully.");
+
+    Ok(())
+}
+
+// Extracts the integer version components out of a tag name the same way
+// `find_latest_version` parses `app-x.xx.x` directory names, so two tags can
+// be compared without caring about `v` prefixes. Only the digits are kept, so
+// the separators are lost: `1.02` and `1.2` give different numbers.
+fn parse_version_components(tag: &str) -> i64 {
+    tag.chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse::<i64>()
+        .unwrap_or(0)
+}
+
+pub fn status(install: &LunaInstall) -> Result<()> {
+    if !install.installed() {
+        info!("luna is not installed.");
+        return Ok(());
+    }
+
+    match manifest::read(install)? {
+        Some(manifest) => {
+            info!(
+                "Installed luna version: {} ({})",
+                manifest.tag_name, manifest.asset_url
+            );
+        }
+        None => {
+            info!("luna is installed but its version is unknown (no install manifest found).");
+        }
+    }
+    Ok(())
+}
+
+pub fn update(install: &LunaInstall, fetch: &FetchOptions) -> Result<()> {
+    if !install.installed() {
+        anyhow::bail!("luna is not installed. Use `install` first.");
     }
 
+    let installed_tag = manifest::read(install)?.map(|m| m.tag_name);
+
+    // --no-download/--from-file mean "don't hit the GitHub API", so skip the
+    // version comparison entirely rather than failing offline; download_and_extract
+    // below already knows how to honor both of these on its own.
+    if fetch.no_download || fetch.from_file.is_some() {
+        match &installed_tag {
+            Some(installed_tag) => info!("Updating luna from {installed_tag} using a local copy"),
+            None => info!("Installed luna version is unknown, updating from a local copy"),
+        }
+    } else {
+        let (remote_tag, _, _) = get_latest_release(fetch.prerelease, fetch.tag.as_deref())?;
+
+        let up_to_date = installed_tag
+            .as_deref()
+            .map(|installed| parse_version_components(installed) == parse_version_components(&remote_tag))
+            .unwrap_or(false);
+
+        if up_to_date {
+            info!("luna is already up to date ({remote_tag}).");
+            return Ok(());
+        }
+
+        match &installed_tag {
+            Some(installed_tag) => info!("Updating luna from {installed_tag} to {remote_tag}"),
+            None => info!("Installed luna version is unknown, updating to {remote_tag}"),
+        }
+    }
+
+    // Download, verify and extract the new release into temp_path *before*
+    // touching the existing app folder, so a failure here (network, bad
+    // checksum, bad zip) leaves the current install untouched instead of
+    // trading it for an empty app_path.
     info!(
         "Downloading & extracting luna to {}",
         install.temp_path.display()
     );
-    download_and_extract(&install.temp_path)?;
+    let (tag_name, asset_url) = download_and_extract(&install.temp_path, fetch)?;
     if !install.temp_path.exists() {
         anyhow::bail!(
             "luna injector failed to extract to {}",
@@ -110,6 +571,12 @@ pub fn install(install: &LunaInstall, force: bool) -> Result<()> {
         );
     }
 
+    info!(
+        "Removing old luna app folder {}",
+        install.app_path.display()
+    );
+    std::fs::remove_dir_all(&install.app_path)?;
+
     info!("Installing luna to {}", install.app_path.display());
     std::fs::rename(&install.temp_path, &install.app_path).with_context(|| {
         format!(
@@ -119,7 +586,9 @@ pub fn install(install: &LunaInstall, force: bool) -> Result<()> {
         )
     })?;
 
-    info!("luna has been installed successfully.");
+    manifest::write(install, &tag_name, &asset_url)?;
+
+    info!("luna has been updated successfully.");
 
     Ok(())
 }
@@ -157,7 +626,7 @@ mod tests {
 
         mock_install_fs(&luna)?;
 
-        assert!(install(&luna, false).is_ok());
+        assert!(install(&luna, false, &FetchOptions::default()).is_ok());
 
         assert_install_success(&luna)?;
 
@@ -173,10 +642,68 @@ mod tests {
         // Create a mock existing app directory
         fs::create_dir(&luna.app_path)?;
 
-        assert!(install(&luna, true).is_ok());
+        assert!(install(&luna, true, &FetchOptions::default()).is_ok());
 
         assert_install_success(&luna)?;
 
         Ok(())
     }
+
+    fn asset(name: &str) -> GithubAsset {
+        GithubAsset {
+            name: name.to_string(),
+            browser_download_url: format!("https://example.com/{name}"),
+        }
+    }
+
+    #[test]
+    fn test_hex_decode() {
+        assert_eq!(hex_decode("deadbeef").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(hex_decode("").unwrap(), Vec::<u8>::new());
+        assert!(hex_decode("abc").is_err());
+        assert!(hex_decode("zz").is_err());
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+    }
+
+    #[test]
+    fn test_parse_version_components() {
+        assert_eq!(parse_version_components("v1.2.3"), 123);
+        assert_eq!(parse_version_components("5.10.0"), 5100);
+        assert_eq!(parse_version_components("no-digits-here"), 0);
+    }
+
+    #[test]
+    fn test_find_zip_asset() {
+        let assets = vec![asset("luna.zip.sha256"), asset("luna.zip")];
+        let found = find_zip_asset(&assets).expect("should find luna.zip");
+        assert_eq!(found.name, "luna.zip");
+
+        let assets = vec![asset("luna.zip.sha256"), asset("luna.zip.sig")];
+        assert!(find_zip_asset(&assets).is_none());
+    }
+
+    #[test]
+    fn test_cached_zip_path_rejects_path_traversal() {
+        assert!(cached_zip_path("../../etc/passwd").is_err());
+        assert!(cached_zip_path("..").is_err());
+        assert!(cached_zip_path("/etc/passwd").is_err());
+        assert!(cached_zip_path("").is_err());
+        assert!(cached_zip_path("v1.2.3").is_ok());
+    }
+
+    #[test]
+    fn test_find_sibling_asset() {
+        let assets = vec![asset("luna.zip"), asset("luna.zip.sha256"), asset("luna.zip.sig")];
+        assert_eq!(
+            find_sibling_asset(&assets, "luna.zip", ".sha256").unwrap().name,
+            "luna.zip.sha256"
+        );
+        assert!(find_sibling_asset(&assets, "luna.zip", ".asc").is_none());
+    }
 }
@@ -14,9 +14,10 @@ mod progress;
 
 mod base;
 mod install;
+mod manifest;
 mod uninstall;
 
-use crate::install::install;
+use crate::install::{install, status, update, FetchOptions};
 use crate::uninstall::uninstall;
 
 /// A CLI tool to manage Luna on your system
@@ -34,6 +35,10 @@ enum Commands {
     Install(MainOpts),
     #[clap(about = "Uninstall Luna")]
     Uninstall(MainOpts),
+    #[clap(about = "Show the currently installed Luna version")]
+    Status(StatusOpts),
+    #[clap(about = "Update Luna to the latest release")]
+    Update(MainOpts),
 }
 
 #[derive(clap::Args)]
@@ -51,6 +56,55 @@ struct MainOpts {
         help = "The directory where app.asar or original.asar is found. Typically found in TIDAL\\app-x.xx.x\\resources"
     )]
     install_path: Option<PathBuf>,
+
+    #[clap(
+        long,
+        default_value = None,
+        help = "Install the release with this exact tag, regardless of its draft/prerelease status"
+    )]
+    tag: Option<String>,
+
+    #[clap(
+        long,
+        action = clap::ArgAction::SetTrue,
+        help = "Install the latest prerelease instead of the latest stable release"
+    )]
+    prerelease: bool,
+
+    #[clap(
+        long,
+        default_value = None,
+        help = "Install from a local luna.zip instead of fetching release metadata from GitHub"
+    )]
+    from_file: Option<PathBuf>,
+
+    #[clap(
+        long,
+        action = clap::ArgAction::SetTrue,
+        help = "Don't hit the GitHub API; reuse the cached luna.zip for --tag (--tag is required)"
+    )]
+    no_download: bool,
+}
+
+impl MainOpts {
+    fn fetch_options(&self) -> FetchOptions {
+        FetchOptions {
+            tag: self.tag.clone(),
+            prerelease: self.prerelease,
+            from_file: self.from_file.clone(),
+            no_download: self.no_download,
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct StatusOpts {
+    #[clap(
+        long,
+        default_value = None,
+        help = "The directory where app.asar or original.asar is found. Typically found in TIDAL\\app-x.xx.x\\resources"
+    )]
+    install_path: Option<PathBuf>,
 }
 
 fn main() {
@@ -91,12 +145,19 @@ fn run() -> Result<()> {
     }
 
     match &cli.command {
-        Some(Commands::Install(opts)) => {
-            install(&LunaInstall::new(opts.install_path.clone())?, opts.force)
-        }
+        Some(Commands::Install(opts)) => install(
+            &LunaInstall::new(opts.install_path.clone())?,
+            opts.force,
+            &opts.fetch_options(),
+        ),
         Some(Commands::Uninstall(opts)) => {
             uninstall(&LunaInstall::new(opts.install_path.clone())?, opts.force)
         }
+        Some(Commands::Status(opts)) => status(&LunaInstall::new(opts.install_path.clone())?),
+        Some(Commands::Update(opts)) => update(
+            &LunaInstall::new(opts.install_path.clone())?,
+            &opts.fetch_options(),
+        ),
         None => {
             Cli::command().print_help()?;
             println!("\nNo commands specified! Using defaults...");
@@ -113,7 +174,7 @@ fn run() -> Result<()> {
             if installed {
                 uninstall(&luna, false)?
             } else {
-                install(&luna, false)?
+                install(&luna, false, &FetchOptions::default())?
             }
 
             println!(
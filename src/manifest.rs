@@ -0,0 +1,66 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::base::LunaInstall;
+
+const MANIFEST_FILE_NAME: &str = "luna-manifest.json";
+
+/// Records which release is currently installed so `status`/`update` don't
+/// have to guess by poking around the extracted `app` folder.
+#[derive(Serialize, Deserialize)]
+pub struct InstallManifest {
+    pub tag_name: String,
+    pub asset_url: String,
+    pub installed_at: u64,
+}
+
+impl InstallManifest {
+    fn new(tag_name: &str, asset_url: &str) -> Self {
+        let installed_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            tag_name: tag_name.to_string(),
+            asset_url: asset_url.to_string(),
+            installed_at,
+        }
+    }
+}
+
+fn manifest_path(install: &LunaInstall) -> std::path::PathBuf {
+    install.app_path.join(MANIFEST_FILE_NAME)
+}
+
+/// Writes the manifest for a freshly installed release. Called after
+/// `app_path` exists so the manifest lives alongside the injector it describes.
+pub fn write(install: &LunaInstall, tag_name: &str, asset_url: &str) -> Result<()> {
+    let manifest = InstallManifest::new(tag_name, asset_url);
+    let path = manifest_path(install);
+    debug!("Writing install manifest to {}", path.display());
+    std::fs::write(&path, serde_json::to_string_pretty(&manifest)?)
+        .with_context(|| format!("Failed to write install manifest to {}", path.display()))?;
+    Ok(())
+}
+
+/// Reads the manifest, if any. Missing or unparsable manifests are treated as
+/// "version unknown" rather than an error, since older installs won't have one.
+pub fn read(install: &LunaInstall) -> Result<Option<InstallManifest>> {
+    let path = manifest_path(install);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read install manifest at {}", path.display()))?;
+    match serde_json::from_str(&contents) {
+        Ok(manifest) => Ok(Some(manifest)),
+        Err(e) => {
+            debug!("Failed to parse install manifest at {}: {e}", path.display());
+            Ok(None)
+        }
+    }
+}
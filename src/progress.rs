@@ -21,13 +21,40 @@ impl UnzipProgressReporter for ProgressDisplayer {
 
     fn total_bytes_expected(&self, expected: u64) {
         self.0.set_length(expected);
-        self.0.set_style(ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({eta})\n{msg}")
-        .unwrap()
-        .with_key("eta", |state: &ProgressState, w: &mut dyn Write| write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap())
-        .progress_chars("#-"));
+        self.0.set_style(download_progress_style());
     }
 
     fn bytes_extracted(&self, count: u64) {
         self.0.inc(count)
     }
 }
+
+fn download_progress_style() -> ProgressStyle {
+    ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({eta})\n{msg}")
+        .unwrap()
+        .with_key("eta", |state: &ProgressState, w: &mut dyn Write| write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap())
+        .progress_chars("#-")
+}
+
+/// Reports download (as opposed to extraction) progress, so a resumed
+/// download shows a bar starting from the bytes already on disk instead of
+/// restarting from zero or showing nothing at all.
+pub struct DownloadProgressDisplayer(ProgressBar);
+
+impl DownloadProgressDisplayer {
+    pub fn new(display_name: &str, total_bytes: u64, already_downloaded: u64) -> Self {
+        let bar = ProgressBar::new(total_bytes);
+        bar.set_style(download_progress_style());
+        bar.set_message(format!("Downloading {display_name}"));
+        bar.set_position(already_downloaded);
+        Self(bar)
+    }
+
+    pub fn inc(&self, delta: u64) {
+        self.0.inc(delta);
+    }
+
+    pub fn finish(&self) {
+        self.0.finish_and_clear();
+    }
+}